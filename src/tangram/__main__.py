@@ -19,6 +19,7 @@ from werkzeug.middleware.proxy_fix import ProxyFix
 from . import config_turb
 from .client.turbulence import TurbulenceClient
 from .util import assets
+from .util.cors import cors_resources, validate_cors_origins
 from .views import base_views, history_views
 from .views.requests import RequestBuilder
 
@@ -28,7 +29,6 @@ _log.setLevel(logging.INFO)
 
 app.wsgi_app = ProxyFix(app.wsgi_app, x_proto=1, x_host=1, x_prefix=1)
 
-cors = CORS(app, resources={r"/*": {"origins": "*"}})
 asset = Environment(app)
 asset.register(assets.bundles)
 
@@ -42,6 +42,9 @@ live = int(config_turb.get("tangram", "live", fallback=1))
 history = int(config_turb.get("tangram", "history", fallback=1))
 data_path = config_turb.get("tangram", "path_data", fallback="")
 mongo_uri = config_turb.get("tangram", "database_uri", fallback="")
+cors_origins = config_turb.get("tangram", "cors_origins", fallback="*")
+cors_methods = config_turb.get("tangram", "cors_methods", fallback="")
+cors_headers = config_turb.get("tangram", "cors_headers", fallback="")
 
 
 def memory_limit() -> None:
@@ -68,6 +71,11 @@ def get_memory() -> int:
 @click.option("--history", default=history)
 @click.option("--data_path", default=data_path)
 @click.option("--mongo_uri", default=mongo_uri)
+@click.option(
+    "--cors_origins", default=cors_origins, callback=validate_cors_origins
+)
+@click.option("--cors_methods", default=cors_methods)
+@click.option("--cors_headers", default=cors_headers)
 @click.option(
     "--demo",
     is_flag=True,
@@ -80,10 +88,17 @@ def main(
     history,
     data_path,
     mongo_uri,
+    cors_origins,
+    cors_methods,
+    cors_headers,
     source,
     decoders_address,
     demo,
 ) -> None:
+    CORS(
+        app,
+        resources=cors_resources(cors_origins, cors_methods, cors_headers),
+    )
     memory_limit()
     # with memray.Tracker("output_file.bin",):
     #     print("Allocations will be tracked until the with block ends")
@@ -19,6 +19,7 @@ from atmlab.network import Network
 from flask import Flask
 from pymongo import MongoClient
 from pymongo.errors import DocumentTooLarge, OperationFailure
+from tangram.util.geojson import mask_invalid_position
 from tangram.util.zmq_sockets import DecoderSocket
 from traffic import config
 from traffic.core.traffic import Flight, Traffic
@@ -239,7 +240,8 @@ class Aggregator:
         flight_data = {}
 
         cumul: pd.DataFrame = (
-            flight.data.dropna(
+            flight.data.pipe(mask_invalid_position)
+            .dropna(
                 subset=set(flight.data.columns)
                 - {"timestamp", "callsign", "icao24", "antenna"},
                 how="all",
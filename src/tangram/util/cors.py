@@ -0,0 +1,73 @@
+import re
+from typing import Any, Dict, List
+from urllib.parse import urlparse
+
+import click
+
+
+def split_list(value: str) -> List[str]:
+    return [item for item in re.split(r"[\s,]+", value) if item]
+
+
+def parse_origin(origin: str) -> str:
+    url = urlparse(origin)
+    if url.scheme == "" or url.netloc == "":
+        raise ValueError(
+            f"invalid CORS origin {origin!r}, expected scheme://host[:port]"
+        )
+    if "@" in url.netloc:
+        raise ValueError(
+            f"invalid CORS origin {origin!r}, it must not contain user info"
+        )
+    try:
+        url.port
+    except ValueError:
+        raise ValueError(
+            f"invalid CORS origin {origin!r}, bad port number"
+        ) from None
+    if url.netloc.endswith(":"):
+        raise ValueError(f"invalid CORS origin {origin!r}, empty port number")
+    # browsers send lowercase origins, flask_cors compares them casefolded
+    normalized = f"{url.scheme}://{url.netloc}".lower()
+    if origin.lower() != normalized:
+        raise ValueError(
+            f"invalid CORS origin {origin!r}, "
+            "it must not contain a path, query or fragment"
+        )
+    return normalized
+
+
+def parse_origins(value: str) -> List[str]:
+    origins = split_list(value) or ["*"]
+    if "*" in origins:
+        if len(origins) > 1:
+            raise ValueError(
+                "CORS origin '*' allows every origin "
+                "and cannot be combined with other origins"
+            )
+        return origins
+    return [parse_origin(origin) for origin in origins]
+
+
+def validate_cors_origins(
+    ctx: click.Context, param: click.Parameter, value: str
+) -> List[str]:
+    try:
+        return parse_origins(value)
+    except ValueError as e:
+        raise click.BadParameter(
+            f"{e} (set with --cors_origins or cors_origins in [tangram])",
+            ctx=ctx,
+            param=param,
+        )
+
+
+def cors_resources(
+    origins: List[str], methods: str = "", headers: str = ""
+) -> Dict[str, Dict[str, Any]]:
+    options: Dict[str, Any] = {"origins": origins}
+    if split_list(methods):
+        options["methods"] = split_list(methods)
+    if split_list(headers):
+        options["allow_headers"] = split_list(headers)
+    return {r"/*": options}
@@ -3,12 +3,36 @@ from typing import Any, Dict, List, Optional
 from traffic.core import Traffic
 
 import numpy as np
+import pandas as pd
+
+
+def has_position(latitude: Any, longitude: Any) -> Any:
+    # elementwise, so that it applies to scalars as well as to pd.Series
+    return (
+        np.isfinite(latitude)
+        & np.isfinite(longitude)
+        & (np.abs(latitude) <= 90)
+        & (np.abs(longitude) <= 180)
+    )
+
+
+def valid_position(df: pd.DataFrame) -> pd.Series:
+    return has_position(df.latitude, df.longitude)
+
+
+def mask_invalid_position(df: pd.DataFrame) -> pd.DataFrame:
+    # keep the rows for their other fields, treat the position as missing
+    valid = valid_position(df)
+    return df.assign(
+        latitude=df.latitude.where(valid),
+        longitude=df.longitude.where(valid),
+    )
 
 
 def geojson_flight(stv: list) -> Optional[Dict[str, Any]]:
     latitude = stv["latitude"]
     longitude = stv["longitude"]
-    if not (np.isnan(latitude) and np.isnan(longitude)):
+    if has_position(latitude, longitude):
         track = stv["track"]
         typecode = stv["typecode"]
         x = {
@@ -37,7 +61,8 @@ def geojson_traffic(
     features: List[Optional[Dict[str, Any]]] = []
     if traffic is not None:
         state_vectors = (
-            traffic.data.groupby("icao24", as_index=False)[
+            traffic.data.pipe(mask_invalid_position)
+            .groupby("icao24", as_index=False)[
                 [
                     "icao24",
                     "callsign",
@@ -75,6 +100,10 @@ def geojson_turbulence(pro_data: Optional[Traffic]) -> Dict[str, Any]:
                 typecode = flight.data.typecode.iloc[0]
                 if flight.shape is not None:
                     for segment in flight.split("1T"):
+                        if segment is not None:
+                            segment = segment.assign(
+                                valid_position=valid_position
+                            ).query("valid_position")
                         if segment is not None:
                             x = {"type": "LineString", "coordinates": []}
                             t = []
@@ -14,15 +14,13 @@ from flask import (
     send_from_directory,
     url_for,
 )
-from flask_cors import CORS
 from requests.exceptions import HTTPError
 
 from ..client.turbulence import TurbulenceClient
-from ..util.geojson import geojson_traffic, geojson_turbulence
+from ..util.geojson import geojson_traffic, geojson_turbulence, valid_position
 from ..views.forms import DatabaseForm, ThresholdForm
 
 base_bp = Blueprint("base", __name__)
-CORS(base_bp)
 
 
 @base_bp.route("/stop")
@@ -299,6 +297,11 @@ def get_traj(icao24: str) -> Dict[str, Any]:
             und = int(und) / 1000
             t = pd.Timestamp(und, unit="s", tz="utc")
             flight = flight.query(f"timestamp<='{str(t)}'")
+    if flight is not None:
+        flight = flight.assign(valid_position=valid_position).query(
+            "valid_position"
+        )
+    if flight is not None:
         geojson_f = flight.geojson()
         if geojson_f is not None:
             geojson_f.update(
@@ -7,7 +7,6 @@ from flask import (
     request,
     url_for,
 )
-from flask_cors import CORS
 from pymongo.cursor import Cursor
 from werkzeug.utils import redirect
 
@@ -16,7 +15,6 @@ from pandas import Timestamp
 from ..client.turbulence import TurbulenceClient
 
 history_bp = Blueprint("history", __name__)
-CORS(history_bp)
 
 
 def get_date_file() -> Tuple[Timestamp, Timestamp]:
@@ -0,0 +1,96 @@
+from typing import List
+
+import click
+import pytest
+from click.testing import CliRunner
+from flask import Flask
+from flask_cors import CORS
+
+from tangram.util.cors import (
+    cors_resources,
+    parse_origins,
+    validate_cors_origins,
+)
+
+
+def cors_app(origins: List[str]) -> Flask:
+    app = Flask(__name__)
+    CORS(app, resources=cors_resources(origins))
+
+    @app.route("/uptime")
+    def uptime() -> dict:
+        return {"uptime": 0}
+
+    return app
+
+
+def test_default_origin() -> None:
+    assert parse_origins("") == ["*"]
+    client = cors_app(parse_origins("")).test_client()
+    response = client.get("/uptime", headers={"Origin": "https://a.example"})
+    # without send_wildcard, flask_cors echoes any origin matched by "*"
+    assert (
+        response.headers["Access-Control-Allow-Origin"] == "https://a.example"
+    )
+
+
+def test_allowed_origin() -> None:
+    origins = parse_origins("https://a.example, https://b.example")
+    assert origins == ["https://a.example", "https://b.example"]
+    client = cors_app(origins).test_client()
+    response = client.get("/uptime", headers={"Origin": "https://b.example"})
+    assert (
+        response.headers["Access-Control-Allow-Origin"] == "https://b.example"
+    )
+
+
+def test_disallowed_origin() -> None:
+    client = cors_app(parse_origins("https://a.example")).test_client()
+    response = client.get("/uptime", headers={"Origin": "https://c.example"})
+    assert response.status_code == 200
+    assert "Access-Control-Allow-Origin" not in response.headers
+
+
+@pytest.mark.parametrize(
+    "origin",
+    [
+        "a.example",
+        "https://a.example/",
+        "https://a.example/path",
+        "https://a.example?query=1",
+        "https://a.example#fragment",
+        "https://user@a.example",
+        "https://a.example:",
+        "https://a.example:port",
+    ],
+)
+def test_malformed_origin(origin: str) -> None:
+    with pytest.raises(ValueError, match="invalid CORS origin"):
+        parse_origins(origin)
+
+
+def test_wildcard_with_other_origins() -> None:
+    with pytest.raises(ValueError, match="cannot be combined"):
+        parse_origins("*, https://a.example")
+
+
+def test_origin_case() -> None:
+    origins = parse_origins("HTTPS://A.example")
+    assert origins == ["https://a.example"]
+    client = cors_app(origins).test_client()
+    response = client.get("/uptime", headers={"Origin": "https://a.example"})
+    assert (
+        response.headers["Access-Control-Allow-Origin"] == "https://a.example"
+    )
+
+
+def test_malformed_origin_option() -> None:
+    @click.command()
+    @click.option("--cors_origins", callback=validate_cors_origins)
+    def main(cors_origins: List[str]) -> None:
+        pass
+
+    result = CliRunner().invoke(main, ["--cors_origins", "https://a.example/"])
+    assert result.exit_code == 2
+    assert "--cors_origins" in result.output
+    assert "https://a.example/" in result.output
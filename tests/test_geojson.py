@@ -0,0 +1,107 @@
+import numpy as np
+import pandas as pd
+import pytest
+from traffic.core import Traffic
+
+from tangram.util.geojson import (
+    geojson_flight,
+    geojson_traffic,
+    has_position,
+    mask_invalid_position,
+)
+
+
+@pytest.mark.parametrize(
+    "latitude, longitude",
+    [(43.6, 1.4), (0.0, 0.0), (90.0, 180.0), (-90.0, -180.0)],
+)
+def test_valid_position(latitude: float, longitude: float) -> None:
+    assert has_position(latitude, longitude)
+
+
+@pytest.mark.parametrize(
+    "latitude, longitude",
+    [
+        (np.nan, np.nan),
+        # the previous check only rejected positions where both were NaN
+        (np.nan, 1.4),
+        (43.6, np.nan),
+        (np.inf, 1.4),
+        (43.6, -np.inf),
+    ],
+)
+def test_missing_position(latitude: float, longitude: float) -> None:
+    assert not has_position(latitude, longitude)
+
+
+@pytest.mark.parametrize(
+    "latitude, longitude",
+    [(90.1, 1.4), (-91.0, 1.4), (43.6, 180.5), (43.6, -200.0)],
+)
+def test_out_of_range_position(latitude: float, longitude: float) -> None:
+    assert not has_position(latitude, longitude)
+
+
+def test_position_series() -> None:
+    df = pd.DataFrame(
+        {
+            "latitude": [43.6, np.nan, 95.0, 43.6],
+            "longitude": [1.4, 1.4, 1.4, np.nan],
+        }
+    )
+    valid = has_position(df.latitude, df.longitude)
+    assert valid.tolist() == [True, False, False, False]
+
+
+def test_geojson_flight_without_position() -> None:
+    stv = {
+        "icao24": "39c902",
+        "callsign": "AFR123",
+        "typecode": "A320",
+        "track": 90.0,
+        "latitude": np.nan,
+        "longitude": 1.4,
+    }
+    assert geojson_flight(stv) is None
+    stv["latitude"] = 43.6
+    feature = geojson_flight(stv)
+    assert feature is not None
+    assert feature["geometry"]["coordinates"] == [1.4, 43.6]
+
+
+def test_mask_invalid_position() -> None:
+    df = pd.DataFrame(
+        {
+            "latitude": [43.6, 95.0, np.nan],
+            "longitude": [1.4, 1.4, 1.4],
+            "altitude": [35000, 35100, 35200],
+        }
+    )
+    masked = mask_invalid_position(df)
+    assert len(masked) == 3
+    assert masked.altitude.tolist() == [35000, 35100, 35200]
+    assert masked.latitude.isna().tolist() == [False, True, True]
+    assert masked.longitude.isna().tolist() == [False, True, True]
+
+
+def test_geojson_traffic_keeps_last_valid_position() -> None:
+    t0 = pd.Timestamp("2023-03-01 12:00:00", tz="utc")
+    traffic = Traffic(
+        pd.DataFrame(
+            {
+                "timestamp": [t0, t0 + pd.Timedelta("5s")],
+                "icao24": ["39c902", "39c902"],
+                "callsign": ["AFR123", "AFR123"],
+                "typecode": ["A320", "A320"],
+                "track": [90.0, 91.0],
+                # the last report is out of range, e.g. from a decoder bug
+                "latitude": [43.6, 95.0],
+                "longitude": [1.4, 1.5],
+            }
+        )
+    )
+    result = geojson_traffic(traffic)
+    assert result["count"] == 1
+    (feature,) = result["geojson"]["features"]
+    assert feature["geometry"]["coordinates"] == [1.4, 43.6]
+    assert feature["properties"]["dir"] == 91.0
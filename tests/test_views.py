@@ -0,0 +1,53 @@
+from types import SimpleNamespace
+
+import pandas as pd
+from flask import Flask
+from flask.testing import FlaskClient
+from traffic.core import Traffic
+
+from tangram.views.base_views import base_bp
+
+t0 = pd.Timestamp("2023-03-01 12:00:00", tz="utc")
+
+
+def trajectory_client(latitude: list) -> FlaskClient:
+    n = len(latitude)
+    data = pd.DataFrame(
+        {
+            "timestamp": [t0 + pd.Timedelta(seconds=10 * i) for i in range(n)],
+            "icao24": ["39c902"] * n,
+            "callsign": ["AFR123"] * n,
+            "latitude": latitude,
+            "longitude": [1.4 + 0.1 * i for i in range(n)],
+            "altitude": [35000.0] * n,
+        }
+    )
+    app = Flask(__name__)
+    app.register_blueprint(base_bp)
+    app.live_client = SimpleNamespace(pro_data=Traffic(data))
+    return app.test_client()
+
+
+def test_trajectory_drops_invalid_positions() -> None:
+    client = trajectory_client([43.6, 95.0, 43.7])
+    response = client.get("/trajectory/39c902")
+    assert response.status_code == 200
+    geojson = response.get_json()["geojson"]
+    assert geojson["properties"]["icao"] == "39c902"
+    coordinates = [c[:2] for c in geojson["coordinates"]]
+    assert coordinates == [[1.4, 43.6], [1.6, 43.7]]
+
+
+def test_trajectory_without_valid_position() -> None:
+    client = trajectory_client([95.0, -95.0])
+    response = client.get("/trajectory/39c902")
+    assert response.status_code == 200
+    assert response.get_json() == {"geojson": []}
+
+
+def test_trajectory_before_first_point() -> None:
+    client = trajectory_client([43.6, 43.7])
+    und = (t0 - pd.Timedelta("1 min")).timestamp() * 1000
+    response = client.get(f"/trajectory/39c902?und={und}")
+    assert response.status_code == 200
+    assert response.get_json() == {"geojson": []}